@@ -0,0 +1,433 @@
+// SPDX-License-Identifier: MIT
+
+//! Serialization of an already-tessellated `TruckMeshResult` to on-disk mesh
+//! formats. Operates directly on the flat position/normal/index buffers the
+//! mesh was returned with, so it has no dependency on STEP or triangulation
+//! state.
+
+use std::ffi::CStr;
+use std::fs::File;
+use std::io::Write;
+use std::os::raw::c_char;
+use std::panic;
+use std::ptr;
+
+use crate::{TruckErrorCode, TruckMeshResult};
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub enum TruckExportFormat {
+    StlBinary = 0,
+    StlAscii = 1,
+    Obj = 2,
+    Gltf = 3,
+    Glb = 4,
+}
+
+#[repr(C)]
+pub struct TruckExportBuffer {
+    pub data: *mut u8,
+    pub len: usize,
+    pub capacity: usize,
+}
+
+#[no_mangle]
+pub extern "C" fn truck_export_mesh(
+    result: *const TruckMeshResult,
+    format: TruckExportFormat,
+    out_path: *const c_char,
+) -> i32 {
+    match panic::catch_unwind(|| truck_export_mesh_inner(result, format, out_path)) {
+        Ok(code) => code as i32,
+        Err(_) => TruckErrorCode::ErrPanic as i32,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn truck_export_mesh_to_memory(
+    result: *const TruckMeshResult,
+    format: TruckExportFormat,
+) -> *mut TruckExportBuffer {
+    match panic::catch_unwind(|| truck_export_mesh_to_memory_inner(result, format)) {
+        Ok(buffer_ptr) => buffer_ptr,
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn truck_free_export_buffer(buffer_ptr: *mut TruckExportBuffer) {
+    if buffer_ptr.is_null() {
+        return;
+    }
+
+    unsafe {
+        let buffer = Box::from_raw(buffer_ptr);
+        if !buffer.data.is_null() && buffer.capacity > 0 {
+            let _ = Vec::from_raw_parts(buffer.data, buffer.len, buffer.capacity);
+        }
+    }
+}
+
+fn truck_export_mesh_inner(
+    result: *const TruckMeshResult,
+    format: TruckExportFormat,
+    out_path: *const c_char,
+) -> TruckErrorCode {
+    if out_path.is_null() {
+        return TruckErrorCode::ErrInvalidArgument;
+    }
+
+    let path = unsafe { CStr::from_ptr(out_path) };
+    let path = match path.to_str() {
+        Ok(value) => value.trim(),
+        Err(_) => return TruckErrorCode::ErrInvalidArgument,
+    };
+
+    if path.is_empty() {
+        return TruckErrorCode::ErrInvalidArgument;
+    }
+
+    let bytes = match encode_mesh(result, format) {
+        Ok(bytes) => bytes,
+        Err(code) => return code,
+    };
+
+    match File::create(path).and_then(|mut file| file.write_all(&bytes)) {
+        Ok(()) => TruckErrorCode::Ok,
+        Err(_) => TruckErrorCode::ErrIo,
+    }
+}
+
+fn truck_export_mesh_to_memory_inner(
+    result: *const TruckMeshResult,
+    format: TruckExportFormat,
+) -> *mut TruckExportBuffer {
+    let mut bytes = match encode_mesh(result, format) {
+        Ok(bytes) => bytes,
+        Err(_) => return ptr::null_mut(),
+    };
+
+    let buffer = TruckExportBuffer {
+        data: bytes.as_mut_ptr(),
+        len: bytes.len(),
+        capacity: bytes.capacity(),
+    };
+    std::mem::forget(bytes);
+    Box::into_raw(Box::new(buffer))
+}
+
+struct MeshView<'a> {
+    positions: &'a [f32],
+    indices: &'a [u32],
+    normals: Option<&'a [f32]>,
+    bbox_min: [f64; 3],
+    bbox_max: [f64; 3],
+}
+
+fn encode_mesh(result: *const TruckMeshResult, format: TruckExportFormat) -> Result<Vec<u8>, TruckErrorCode> {
+    if result.is_null() {
+        return Err(TruckErrorCode::ErrInvalidArgument);
+    }
+
+    let result = unsafe { &*result };
+    if result.error_code != TruckErrorCode::Ok as i32 || result.vertices.is_null() || result.indices.is_null() {
+        return Err(TruckErrorCode::ErrInvalidArgument);
+    }
+
+    let positions = unsafe { std::slice::from_raw_parts(result.vertices, result.vertex_count) };
+    let indices = unsafe { std::slice::from_raw_parts(result.indices, result.index_count) };
+    let normals = if result.normals.is_null() {
+        None
+    } else {
+        Some(unsafe { std::slice::from_raw_parts(result.normals, result.normal_count) })
+    };
+
+    let mesh = MeshView {
+        positions,
+        indices,
+        normals,
+        bbox_min: [result.bbox_min_x, result.bbox_min_y, result.bbox_min_z],
+        bbox_max: [result.bbox_max_x, result.bbox_max_y, result.bbox_max_z],
+    };
+
+    Ok(match format {
+        TruckExportFormat::StlBinary => write_stl_binary(&mesh),
+        TruckExportFormat::StlAscii => write_stl_ascii(&mesh),
+        TruckExportFormat::Obj => write_obj(&mesh),
+        TruckExportFormat::Gltf => write_gltf(&mesh),
+        TruckExportFormat::Glb => write_glb(&mesh),
+    })
+}
+
+fn triangle(mesh: &MeshView, tri: usize) -> [[f32; 3]; 3] {
+    let mut points = [[0.0_f32; 3]; 3];
+    for (corner, point) in points.iter_mut().enumerate() {
+        let index = mesh.indices[tri * 3 + corner] as usize;
+        point[0] = mesh.positions[index * 3];
+        point[1] = mesh.positions[index * 3 + 1];
+        point[2] = mesh.positions[index * 3 + 2];
+    }
+    points
+}
+
+fn facet_normal(points: &[[f32; 3]; 3]) -> [f32; 3] {
+    let u = [
+        points[1][0] - points[0][0],
+        points[1][1] - points[0][1],
+        points[1][2] - points[0][2],
+    ];
+    let v = [
+        points[2][0] - points[0][0],
+        points[2][1] - points[0][1],
+        points[2][2] - points[0][2],
+    ];
+    let cross = [
+        u[1] * v[2] - u[2] * v[1],
+        u[2] * v[0] - u[0] * v[2],
+        u[0] * v[1] - u[1] * v[0],
+    ];
+    let length = (cross[0] * cross[0] + cross[1] * cross[1] + cross[2] * cross[2]).sqrt();
+    if length > 0.0 {
+        [cross[0] / length, cross[1] / length, cross[2] / length]
+    } else {
+        [0.0, 0.0, 0.0]
+    }
+}
+
+fn write_stl_binary(mesh: &MeshView) -> Vec<u8> {
+    let triangle_count = mesh.indices.len() / 3;
+    let mut bytes = Vec::with_capacity(80 + 4 + triangle_count * 50);
+    bytes.extend_from_slice(&[0u8; 80]);
+    bytes.extend_from_slice(&(triangle_count as u32).to_le_bytes());
+
+    for tri in 0..triangle_count {
+        let points = triangle(mesh, tri);
+        let normal = facet_normal(&points);
+        for component in normal {
+            bytes.extend_from_slice(&component.to_le_bytes());
+        }
+        for point in &points {
+            for component in point {
+                bytes.extend_from_slice(&component.to_le_bytes());
+            }
+        }
+        bytes.extend_from_slice(&0u16.to_le_bytes());
+    }
+
+    bytes
+}
+
+fn write_stl_ascii(mesh: &MeshView) -> Vec<u8> {
+    let triangle_count = mesh.indices.len() / 3;
+    let mut text = String::from("solid truck_export\n");
+
+    for tri in 0..triangle_count {
+        let points = triangle(mesh, tri);
+        let normal = facet_normal(&points);
+        text.push_str(&format!(
+            "  facet normal {} {} {}\n    outer loop\n",
+            normal[0], normal[1], normal[2]
+        ));
+        for point in &points {
+            text.push_str(&format!("      vertex {} {} {}\n", point[0], point[1], point[2]));
+        }
+        text.push_str("    endloop\n  endfacet\n");
+    }
+
+    text.push_str("endsolid truck_export\n");
+    text.into_bytes()
+}
+
+fn write_obj(mesh: &MeshView) -> Vec<u8> {
+    let vertex_count = mesh.positions.len() / 3;
+    let mut text = String::new();
+
+    for vertex in 0..vertex_count {
+        text.push_str(&format!(
+            "v {} {} {}\n",
+            mesh.positions[vertex * 3],
+            mesh.positions[vertex * 3 + 1],
+            mesh.positions[vertex * 3 + 2]
+        ));
+    }
+
+    if let Some(normals) = mesh.normals {
+        for vertex in 0..vertex_count {
+            text.push_str(&format!(
+                "vn {} {} {}\n",
+                normals[vertex * 3],
+                normals[vertex * 3 + 1],
+                normals[vertex * 3 + 2]
+            ));
+        }
+    }
+
+    for tri in mesh.indices.chunks_exact(3) {
+        if mesh.normals.is_some() {
+            text.push_str(&format!(
+                "f {0}//{0} {1}//{1} {2}//{2}\n",
+                tri[0] + 1,
+                tri[1] + 1,
+                tri[2] + 1
+            ));
+        } else {
+            text.push_str(&format!("f {} {} {}\n", tri[0] + 1, tri[1] + 1, tri[2] + 1));
+        }
+    }
+
+    text.into_bytes()
+}
+
+fn gltf_buffer_bytes(mesh: &MeshView) -> Vec<u8> {
+    let mut buffer = Vec::with_capacity(
+        mesh.positions.len() * 4 + mesh.normals.map_or(0, |n| n.len() * 4) + mesh.indices.len() * 4,
+    );
+    for component in mesh.positions {
+        buffer.extend_from_slice(&component.to_le_bytes());
+    }
+    if let Some(normals) = mesh.normals {
+        for component in normals {
+            buffer.extend_from_slice(&component.to_le_bytes());
+        }
+    }
+    for index in mesh.indices {
+        buffer.extend_from_slice(&index.to_le_bytes());
+    }
+    buffer
+}
+
+fn gltf_json(mesh: &MeshView, buffer_byte_length: usize, buffer_uri: Option<&str>) -> String {
+    let vertex_count = mesh.positions.len() / 3;
+    let position_bytes = mesh.positions.len() * 4;
+    let normal_bytes = mesh.normals.map_or(0, |n| n.len() * 4);
+
+    let mut accessors = format!(
+        concat!(
+            "{{\"bufferView\":0,\"componentType\":5126,\"count\":{},\"type\":\"VEC3\",",
+            "\"min\":[{},{},{}],\"max\":[{},{},{}]}}"
+        ),
+        vertex_count,
+        mesh.bbox_min[0],
+        mesh.bbox_min[1],
+        mesh.bbox_min[2],
+        mesh.bbox_max[0],
+        mesh.bbox_max[1],
+        mesh.bbox_max[2],
+    );
+
+    let mut buffer_views = format!(
+        "{{\"buffer\":0,\"byteOffset\":0,\"byteLength\":{position_bytes},\"target\":34962}}"
+    );
+
+    let normal_accessor_index;
+    if mesh.normals.is_some() {
+        buffer_views.push_str(&format!(
+            ",{{\"buffer\":0,\"byteOffset\":{position_bytes},\"byteLength\":{normal_bytes},\"target\":34962}}"
+        ));
+        accessors.push_str(&format!(
+            ",{{\"bufferView\":1,\"componentType\":5126,\"count\":{vertex_count},\"type\":\"VEC3\"}}"
+        ));
+        normal_accessor_index = Some(1);
+    } else {
+        normal_accessor_index = None;
+    }
+
+    let index_buffer_view = if mesh.normals.is_some() { 2 } else { 1 };
+    let index_accessor = if mesh.normals.is_some() { 2 } else { 1 };
+    buffer_views.push_str(&format!(
+        ",{{\"buffer\":0,\"byteOffset\":{},\"byteLength\":{},\"target\":34963}}",
+        position_bytes + normal_bytes,
+        mesh.indices.len() * 4
+    ));
+    accessors.push_str(&format!(
+        ",{{\"bufferView\":{index_buffer_view},\"componentType\":5125,\"count\":{},\"type\":\"SCALAR\"}}",
+        mesh.indices.len()
+    ));
+
+    let attributes = match normal_accessor_index {
+        Some(normal_index) => format!("\"POSITION\":0,\"NORMAL\":{normal_index}"),
+        None => String::from("\"POSITION\":0"),
+    };
+
+    let buffer = match buffer_uri {
+        Some(uri) => format!("{{\"byteLength\":{buffer_byte_length},\"uri\":\"{uri}\"}}"),
+        None => format!("{{\"byteLength\":{buffer_byte_length}}}"),
+    };
+
+    format!(
+        concat!(
+            "{{\"asset\":{{\"version\":\"2.0\",\"generator\":\"truck_ffi\"}},",
+            "\"scene\":0,\"scenes\":[{{\"nodes\":[0]}}],",
+            "\"nodes\":[{{\"mesh\":0}}],",
+            "\"meshes\":[{{\"primitives\":[{{\"attributes\":{{{attributes}}},\"indices\":{index_accessor},\"mode\":4}}]}}],",
+            "\"accessors\":[{accessors}],",
+            "\"bufferViews\":[{buffer_views}],",
+            "\"buffers\":[{buffer}]}}"
+        ),
+        attributes = attributes,
+        index_accessor = index_accessor,
+        accessors = accessors,
+        buffer_views = buffer_views,
+        buffer = buffer,
+    )
+}
+
+fn write_gltf(mesh: &MeshView) -> Vec<u8> {
+    let buffer_bytes = gltf_buffer_bytes(mesh);
+    let uri = format!("data:application/octet-stream;base64,{}", base64_encode(&buffer_bytes));
+    gltf_json(mesh, buffer_bytes.len(), Some(&uri)).into_bytes()
+}
+
+fn write_glb(mesh: &MeshView) -> Vec<u8> {
+    let buffer_bytes = gltf_buffer_bytes(mesh);
+    let mut json = gltf_json(mesh, buffer_bytes.len(), None).into_bytes();
+    while json.len() % 4 != 0 {
+        json.push(b' ');
+    }
+
+    let mut bin = buffer_bytes;
+    while bin.len() % 4 != 0 {
+        bin.push(0);
+    }
+
+    let total_length = 12 + 8 + json.len() + 8 + bin.len();
+    let mut glb = Vec::with_capacity(total_length);
+    glb.extend_from_slice(b"glTF");
+    glb.extend_from_slice(&2u32.to_le_bytes());
+    glb.extend_from_slice(&(total_length as u32).to_le_bytes());
+
+    glb.extend_from_slice(&(json.len() as u32).to_le_bytes());
+    glb.extend_from_slice(b"JSON");
+    glb.extend_from_slice(&json);
+
+    glb.extend_from_slice(&(bin.len() as u32).to_le_bytes());
+    glb.extend_from_slice(b"BIN\0");
+    glb.extend_from_slice(&bin);
+
+    glb
+}
+
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity((bytes.len() + 2) / 3 * 4);
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = *chunk.get(1).unwrap_or(&0);
+        let b2 = *chunk.get(2).unwrap_or(&0);
+
+        out.push(BASE64_ALPHABET[(b0 >> 2) as usize] as char);
+        out.push(BASE64_ALPHABET[(((b0 & 0x03) << 4) | (b1 >> 4)) as usize] as char);
+        out.push(if chunk.len() > 1 {
+            BASE64_ALPHABET[(((b1 & 0x0f) << 2) | (b2 >> 6)) as usize] as char
+        } else {
+            '='
+        });
+        out.push(if chunk.len() > 2 {
+            BASE64_ALPHABET[(b2 & 0x3f) as usize] as char
+        } else {
+            '='
+        });
+    }
+    out
+}
@@ -1,11 +1,16 @@
 // SPDX-License-Identifier: MIT
 
+mod export;
+
+pub use export::{TruckExportBuffer, TruckExportFormat};
+
 use std::ffi::{CStr, CString};
 use std::mem;
 use std::os::raw::c_char;
 use std::panic;
 use std::ptr;
 
+use rayon::prelude::*;
 use truck_meshalgo::prelude::*;
 use truck_polymesh::PolygonMesh;
 use truck_stepio::r#in::Table;
@@ -27,9 +32,15 @@ pub struct TruckMeshResult {
     pub vertices: *mut f32,
     pub vertex_count: usize,
     pub vertex_capacity: usize,
+    pub normals: *mut f32,
+    pub normal_count: usize,
+    pub normal_capacity: usize,
     pub indices: *mut u32,
     pub index_count: usize,
     pub index_capacity: usize,
+    pub submeshes: *mut TruckSubmesh,
+    pub submesh_count: usize,
+    pub submesh_capacity: usize,
     pub volume: f64,
     pub surface_area: f64,
     pub bbox_min_x: f64,
@@ -43,9 +54,20 @@ pub struct TruckMeshResult {
     pub error_message: *mut c_char,
 }
 
+/// The index range in `TruckMeshResult::indices` contributed by a single
+/// `table.shell` entry, in the order the shells were visited.
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct TruckSubmesh {
+    pub index_offset: u32,
+    pub index_count: u32,
+}
+
 struct OwnedMeshData {
     vertices: Vec<f32>,
+    normals: Vec<f32>,
     indices: Vec<u32>,
+    submeshes: Vec<TruckSubmesh>,
     volume: f64,
     surface_area: f64,
     bbox_min: [f64; 3],
@@ -53,6 +75,32 @@ struct OwnedMeshData {
     watertight: bool,
 }
 
+/// Named tessellation budgets for callers that don't want to hand-tune
+/// `linear_deflection`/`angular_deflection` directly. Each preset supplies
+/// the defaults used when the corresponding field in
+/// `TruckTessellationParams` is left at `0.0` or `NaN`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub enum TruckQualityPreset {
+    Coarse = 0,
+    Medium = 1,
+    Fine = 2,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct TruckTessellationParams {
+    pub linear_deflection: f64,
+    pub angular_deflection: f64,
+    pub quality_preset: TruckQualityPreset,
+    /// Upper bound on the threads used to tessellate shells in parallel.
+    /// `0` defers to rayon's library-wide default (usually the number of
+    /// logical CPUs).
+    pub max_threads: u32,
+}
+
+const DEFAULT_ANGULAR_DEFLECTION: f64 = 0.35;
+
 #[no_mangle]
 pub extern "C" fn truck_load_step(step_path: *const c_char, linear_deflection: f64) -> *mut TruckMeshResult {
     match panic::catch_unwind(|| truck_load_step_inner(step_path, linear_deflection)) {
@@ -61,6 +109,29 @@ pub extern "C" fn truck_load_step(step_path: *const c_char, linear_deflection: f
     }
 }
 
+#[no_mangle]
+pub extern "C" fn truck_load_step_from_memory(
+    data: *const u8,
+    len: usize,
+    linear_deflection: f64,
+) -> *mut TruckMeshResult {
+    match panic::catch_unwind(|| truck_load_step_from_memory_inner(data, len, linear_deflection)) {
+        Ok(ptr) => ptr,
+        Err(_) => make_error_result(TruckErrorCode::ErrPanic, "panic while processing STEP file"),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn truck_load_step_ex(
+    step_path: *const c_char,
+    params: TruckTessellationParams,
+) -> *mut TruckMeshResult {
+    match panic::catch_unwind(|| truck_load_step_ex_inner(step_path, params)) {
+        Ok(ptr) => ptr,
+        Err(_) => make_error_result(TruckErrorCode::ErrPanic, "panic while processing STEP file"),
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn truck_free_result(result_ptr: *mut TruckMeshResult) {
     if result_ptr.is_null() {
@@ -74,10 +145,18 @@ pub extern "C" fn truck_free_result(result_ptr: *mut TruckMeshResult) {
             let _ = Vec::from_raw_parts(result.vertices, result.vertex_count, result.vertex_capacity);
         }
 
+        if !result.normals.is_null() && result.normal_capacity > 0 {
+            let _ = Vec::from_raw_parts(result.normals, result.normal_count, result.normal_capacity);
+        }
+
         if !result.indices.is_null() && result.index_capacity > 0 {
             let _ = Vec::from_raw_parts(result.indices, result.index_count, result.index_capacity);
         }
 
+        if !result.submeshes.is_null() && result.submesh_capacity > 0 {
+            let _ = Vec::from_raw_parts(result.submeshes, result.submesh_count, result.submesh_capacity);
+        }
+
         if !result.error_message.is_null() {
             let _ = CString::from_raw(result.error_message);
         }
@@ -114,7 +193,61 @@ fn truck_load_step_inner(step_path: *const c_char, linear_deflection: f64) -> *m
         );
     }
 
-    match load_mesh(path, linear_deflection) {
+    let step_file = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(error) => {
+            return make_error_result(TruckErrorCode::ErrIo, format!("failed to read STEP file: {error}"))
+        }
+    };
+
+    match load_mesh(&step_file, linear_deflection, DEFAULT_ANGULAR_DEFLECTION, 0) {
+        Ok(mesh) => {
+            let result = success_result(mesh);
+            Box::into_raw(Box::new(result))
+        }
+        Err((code, message)) => make_error_result(code, message),
+    }
+}
+
+fn truck_load_step_ex_inner(
+    step_path: *const c_char,
+    params: TruckTessellationParams,
+) -> *mut TruckMeshResult {
+    if step_path.is_null() {
+        return make_error_result(TruckErrorCode::ErrInvalidArgument, "step_path must not be null");
+    }
+
+    let (linear_deflection, angular_deflection) = match resolve_tessellation_params(params) {
+        Ok(values) => values,
+        Err((code, message)) => return make_error_result(code, message),
+    };
+
+    let path = unsafe { CStr::from_ptr(step_path) };
+    let path = match path.to_str() {
+        Ok(value) => value.trim(),
+        Err(_) => {
+            return make_error_result(
+                TruckErrorCode::ErrInvalidArgument,
+                "step_path must be valid UTF-8",
+            )
+        }
+    };
+
+    if path.is_empty() {
+        return make_error_result(
+            TruckErrorCode::ErrInvalidArgument,
+            "step_path must be a non-empty path",
+        );
+    }
+
+    let step_file = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(error) => {
+            return make_error_result(TruckErrorCode::ErrIo, format!("failed to read STEP file: {error}"))
+        }
+    };
+
+    match load_mesh(&step_file, linear_deflection, angular_deflection, params.max_threads) {
         Ok(mesh) => {
             let result = success_result(mesh);
             Box::into_raw(Box::new(result))
@@ -123,11 +256,86 @@ fn truck_load_step_inner(step_path: *const c_char, linear_deflection: f64) -> *m
     }
 }
 
-fn load_mesh(path: &str, linear_deflection: f64) -> Result<OwnedMeshData, (TruckErrorCode, String)> {
-    let step_file = std::fs::read_to_string(path)
-        .map_err(|error| (TruckErrorCode::ErrIo, format!("failed to read STEP file: {error}")))?;
+fn resolve_tessellation_params(
+    params: TruckTessellationParams,
+) -> Result<(f64, f64), (TruckErrorCode, String)> {
+    let (default_linear, default_angular) = match params.quality_preset {
+        TruckQualityPreset::Coarse => (0.5, 0.6),
+        TruckQualityPreset::Medium => (0.1, DEFAULT_ANGULAR_DEFLECTION),
+        TruckQualityPreset::Fine => (0.01, 0.15),
+    };
+
+    let linear_deflection = resolve_deflection(params.linear_deflection, default_linear, "linear_deflection")?;
+    let angular_deflection = resolve_deflection(params.angular_deflection, default_angular, "angular_deflection")?;
+
+    Ok((linear_deflection, angular_deflection))
+}
+
+fn resolve_deflection(value: f64, default: f64, name: &str) -> Result<f64, (TruckErrorCode, String)> {
+    if value == 0.0 || value.is_nan() {
+        return Ok(default);
+    }
+
+    if !value.is_finite() || value < 0.0 {
+        return Err((
+            TruckErrorCode::ErrInvalidArgument,
+            format!("{name} must be a positive finite number, zero, or NaN"),
+        ));
+    }
 
-    let exchange = ruststep::parser::parse(&step_file).map_err(|error| {
+    Ok(value)
+}
+
+fn truck_load_step_from_memory_inner(
+    data: *const u8,
+    len: usize,
+    linear_deflection: f64,
+) -> *mut TruckMeshResult {
+    if data.is_null() {
+        return make_error_result(TruckErrorCode::ErrInvalidArgument, "data must not be null");
+    }
+
+    if !linear_deflection.is_finite() || linear_deflection <= 0.0 {
+        return make_error_result(
+            TruckErrorCode::ErrInvalidArgument,
+            "linear_deflection must be a positive finite number",
+        );
+    }
+
+    let bytes = unsafe { std::slice::from_raw_parts(data, len) };
+    let step_file = match std::str::from_utf8(bytes) {
+        Ok(value) => value,
+        Err(_) => {
+            return make_error_result(
+                TruckErrorCode::ErrInvalidArgument,
+                "data must be valid UTF-8",
+            )
+        }
+    };
+
+    if step_file.trim().is_empty() {
+        return make_error_result(
+            TruckErrorCode::ErrInvalidArgument,
+            "data must be non-empty",
+        );
+    }
+
+    match load_mesh(step_file, linear_deflection, DEFAULT_ANGULAR_DEFLECTION, 0) {
+        Ok(mesh) => {
+            let result = success_result(mesh);
+            Box::into_raw(Box::new(result))
+        }
+        Err((code, message)) => make_error_result(code, message),
+    }
+}
+
+fn load_mesh(
+    step_file: &str,
+    linear_deflection: f64,
+    angular_deflection: f64,
+    max_threads: u32,
+) -> Result<OwnedMeshData, (TruckErrorCode, String)> {
+    let exchange = ruststep::parser::parse(step_file).map_err(|error| {
         (
             TruckErrorCode::ErrStepUnsupportedSchema,
             format!("failed to parse STEP data: {error}"),
@@ -150,101 +358,169 @@ fn load_mesh(path: &str, linear_deflection: f64) -> Result<OwnedMeshData, (Truck
     }
 
     let mut merged = PolygonMesh::default();
-    for shell_holder in table.shell.values() {
-        let shell: CompressedShell<_, _, _> = table.to_compressed_shell(shell_holder).map_err(|error| {
-            (
-                TruckErrorCode::ErrStepUnsupportedSchema,
-                format!("failed to convert STEP shell: {error}"),
-            )
-        })?;
-
-        let mut polygon = shell.robust_triangulation(linear_deflection).to_polygon();
-        polygon
-            .put_together_same_attrs(TOLERANCE * 50.0)
-            .remove_degenerate_faces()
-            .remove_unused_attrs();
+    let mut vertices: Vec<f32> = Vec::new();
+    let mut normals: Vec<[f64; 3]> = Vec::new();
+    let mut indices: Vec<u32> = Vec::new();
+    let mut submeshes: Vec<TruckSubmesh> = Vec::new();
+    let mut bbox_min = [f64::INFINITY; 3];
+    let mut bbox_max = [f64::NEG_INFINITY; 3];
+    let mut surface_area = 0.0_f64;
 
-        if !polygon.positions().is_empty() {
-            merged.merge(polygon);
+    let triangulate_shell = |shell_holder: &_| -> Result<PolygonMesh, (TruckErrorCode, String)> {
+        match panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            let shell: CompressedShell<_, _, _> = table.to_compressed_shell(shell_holder).map_err(|error| {
+                (
+                    TruckErrorCode::ErrStepUnsupportedSchema,
+                    format!("failed to convert STEP shell: {error}"),
+                )
+            })?;
+
+            let mut polygon = shell
+                .robust_triangulation(linear_deflection, angular_deflection)
+                .to_polygon();
+            polygon
+                .put_together_same_attrs(TOLERANCE * 50.0)
+                .remove_degenerate_faces()
+                .remove_unused_attrs();
+
+            Ok(polygon)
+        })) {
+            Ok(result) => result,
+            Err(_) => Err((
+                TruckErrorCode::ErrPanic,
+                String::from("panic while tessellating a shell"),
+            )),
         }
-    }
+    };
 
-    if merged.positions().is_empty() {
-        return Err((
-            TruckErrorCode::ErrTessellationFailed,
-            String::from("tessellation produced no vertices"),
-        ));
-    }
+    let shell_holders: Vec<_> = table.shell.values().collect();
+    let triangulated: Vec<Result<PolygonMesh, (TruckErrorCode, String)>> = if max_threads == 0 {
+        shell_holders.into_par_iter().map(triangulate_shell).collect()
+    } else {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(max_threads as usize)
+            .build()
+            .map_err(|error| {
+                (
+                    TruckErrorCode::ErrTessellationFailed,
+                    format!("failed to build tessellation thread pool: {error}"),
+                )
+            })?;
+        pool.install(|| shell_holders.into_par_iter().map(triangulate_shell).collect())
+    };
 
-    let watertight = matches!(
-        merged.shell_condition(),
-        truck_topology::shell::ShellCondition::Closed
-    );
+    // Shells tessellate independently above; the rest of the merge is kept
+    // serial so the output vertex/index order stays deterministic.
+    for polygon_result in triangulated {
+        let mut polygon = polygon_result?;
 
-    let position_mesh = merged.to_positions_mesh();
-    let positions = position_mesh.positions();
+        if polygon.positions().is_empty() {
+            continue;
+        }
 
-    let mut bbox_min = [f64::INFINITY; 3];
-    let mut bbox_max = [f64::NEG_INFINITY; 3];
-    for point in positions {
-        bbox_min[0] = bbox_min[0].min(point.x);
-        bbox_min[1] = bbox_min[1].min(point.y);
-        bbox_min[2] = bbox_min[2].min(point.z);
-
-        bbox_max[0] = bbox_max[0].max(point.x);
-        bbox_max[1] = bbox_max[1].max(point.y);
-        bbox_max[2] = bbox_max[2].max(point.z);
-    }
+        let index_offset = indices.len() as u32;
+        let position_mesh = polygon.to_positions_mesh();
+        let positions = position_mesh.positions();
+        let vertex_offset = (vertices.len() / 3) as u32;
 
-    let mut vertices = Vec::with_capacity(positions.len() * 3);
-    for point in positions {
-        vertices.push(point.x as f32);
-        vertices.push(point.y as f32);
-        vertices.push(point.z as f32);
-    }
+        for point in positions {
+            bbox_min[0] = bbox_min[0].min(point.x);
+            bbox_min[1] = bbox_min[1].min(point.y);
+            bbox_min[2] = bbox_min[2].min(point.z);
 
-    let faces = position_mesh.faces();
-    let mut indices = Vec::new();
-    let mut surface_area = 0.0_f64;
+            bbox_max[0] = bbox_max[0].max(point.x);
+            bbox_max[1] = bbox_max[1].max(point.y);
+            bbox_max[2] = bbox_max[2].max(point.z);
 
-    for tri in faces.tri_faces() {
-        let tri_indices = [
-            usize_to_u32(tri[0])?,
-            usize_to_u32(tri[1])?,
-            usize_to_u32(tri[2])?,
-        ];
-        indices.extend_from_slice(&tri_indices);
-        surface_area += triangle_area(
-            positions[tri[0]],
-            positions[tri[1]],
-            positions[tri[2]],
-        );
-    }
+            vertices.push(point.x as f32);
+            vertices.push(point.y as f32);
+            vertices.push(point.z as f32);
+        }
 
-    for quad in faces.quad_faces() {
-        let i0 = quad[0];
-        let i1 = quad[1];
-        let i2 = quad[2];
-        let i3 = quad[3];
+        let mut shell_normals = vec![[0.0_f64; 3]; positions.len()];
+        let faces = position_mesh.faces();
+
+        for tri in faces.tri_faces() {
+            let tri_indices = [
+                usize_to_u32(tri[0])?,
+                usize_to_u32(tri[1])?,
+                usize_to_u32(tri[2])?,
+            ];
+            indices.extend(tri_indices.iter().map(|index| index + vertex_offset));
+
+            let cross = triangle_cross(positions[tri[0]], positions[tri[1]], positions[tri[2]]);
+            surface_area += 0.5 * cross_norm(cross);
+            accumulate_normal(&mut shell_normals, tri[0], cross);
+            accumulate_normal(&mut shell_normals, tri[1], cross);
+            accumulate_normal(&mut shell_normals, tri[2], cross);
+        }
 
-        indices.extend_from_slice(&[usize_to_u32(i0)?, usize_to_u32(i1)?, usize_to_u32(i2)?]);
-        indices.extend_from_slice(&[usize_to_u32(i0)?, usize_to_u32(i2)?, usize_to_u32(i3)?]);
+        for quad in faces.quad_faces() {
+            let i0 = quad[0];
+            let i1 = quad[1];
+            let i2 = quad[2];
+            let i3 = quad[3];
+
+            indices.extend(
+                [i0, i1, i2]
+                    .iter()
+                    .map(|index| usize_to_u32(*index).map(|value| value + vertex_offset))
+                    .collect::<Result<Vec<_>, _>>()?,
+            );
+            indices.extend(
+                [i0, i2, i3]
+                    .iter()
+                    .map(|index| usize_to_u32(*index).map(|value| value + vertex_offset))
+                    .collect::<Result<Vec<_>, _>>()?,
+            );
+
+            let cross_a = triangle_cross(positions[i0], positions[i1], positions[i2]);
+            let cross_b = triangle_cross(positions[i0], positions[i2], positions[i3]);
+            surface_area += 0.5 * cross_norm(cross_a);
+            surface_area += 0.5 * cross_norm(cross_b);
+
+            accumulate_normal(&mut shell_normals, i0, cross_a);
+            accumulate_normal(&mut shell_normals, i1, cross_a);
+            accumulate_normal(&mut shell_normals, i2, cross_a);
+            accumulate_normal(&mut shell_normals, i0, cross_b);
+            accumulate_normal(&mut shell_normals, i2, cross_b);
+            accumulate_normal(&mut shell_normals, i3, cross_b);
+        }
 
-        surface_area += triangle_area(positions[i0], positions[i1], positions[i2]);
-        surface_area += triangle_area(positions[i0], positions[i2], positions[i3]);
+        for face in faces.other_faces() {
+            if face.len() < 3 {
+                continue;
+            }
+            let base = face[0];
+            for idx in 1..(face.len() - 1) {
+                let i1 = face[idx];
+                let i2 = face[idx + 1];
+                indices.push(usize_to_u32(base)? + vertex_offset);
+                indices.push(usize_to_u32(i1)? + vertex_offset);
+                indices.push(usize_to_u32(i2)? + vertex_offset);
+
+                let cross = triangle_cross(positions[base], positions[i1], positions[i2]);
+                surface_area += 0.5 * cross_norm(cross);
+                accumulate_normal(&mut shell_normals, base, cross);
+                accumulate_normal(&mut shell_normals, i1, cross);
+                accumulate_normal(&mut shell_normals, i2, cross);
+            }
+        }
+
+        normals.extend(shell_normals);
+        submeshes.push(TruckSubmesh {
+            index_offset,
+            index_count: indices.len() as u32 - index_offset,
+        });
+
+        merged.merge(polygon);
     }
 
-    for polygon in faces.other_faces() {
-        if polygon.len() < 3 {
-            continue;
-        }
-        let base = polygon[0];
-        for idx in 1..(polygon.len() - 1) {
-            let i1 = polygon[idx];
-            let i2 = polygon[idx + 1];
-            indices.extend_from_slice(&[usize_to_u32(base)?, usize_to_u32(i1)?, usize_to_u32(i2)?]);
-            surface_area += triangle_area(positions[base], positions[i1], positions[i2]);
-        }
+    if vertices.is_empty() {
+        return Err((
+            TruckErrorCode::ErrTessellationFailed,
+            String::from("tessellation produced no vertices"),
+        ));
     }
 
     if indices.is_empty() {
@@ -254,9 +530,29 @@ fn load_mesh(path: &str, linear_deflection: f64) -> Result<OwnedMeshData, (Truck
         ));
     }
 
+    let watertight = matches!(
+        merged.shell_condition(),
+        truck_topology::shell::ShellCondition::Closed
+    );
+
+    let normals = normals
+        .into_iter()
+        .flat_map(|normal| {
+            let length = cross_norm(normal);
+            let normal = if length > 0.0 {
+                [normal[0] / length, normal[1] / length, normal[2] / length]
+            } else {
+                [0.0, 0.0, 0.0]
+            };
+            [normal[0] as f32, normal[1] as f32, normal[2] as f32]
+        })
+        .collect();
+
     Ok(OwnedMeshData {
         vertices,
+        normals,
         indices,
+        submeshes,
         volume: merged.volume().abs(),
         surface_area,
         bbox_min,
@@ -265,6 +561,12 @@ fn load_mesh(path: &str, linear_deflection: f64) -> Result<OwnedMeshData, (Truck
     })
 }
 
+fn accumulate_normal(normals: &mut [[f64; 3]], index: usize, cross: [f64; 3]) {
+    normals[index][0] += cross[0];
+    normals[index][1] += cross[1];
+    normals[index][2] += cross[2];
+}
+
 fn usize_to_u32(value: usize) -> Result<u32, (TruckErrorCode, String)> {
     u32::try_from(value).map_err(|_| {
         (
@@ -274,7 +576,10 @@ fn usize_to_u32(value: usize) -> Result<u32, (TruckErrorCode, String)> {
     })
 }
 
-fn triangle_area(p0: Point3, p1: Point3, p2: Point3) -> f64 {
+/// The (unnormalized) cross product of a triangle's edge vectors. Its
+/// magnitude is twice the triangle's area and its direction is the
+/// triangle's face normal, so callers use it for both.
+fn triangle_cross(p0: Point3, p1: Point3, p2: Point3) -> [f64; 3] {
     let ux = p1.x - p0.x;
     let uy = p1.y - p0.y;
     let uz = p1.z - p0.z;
@@ -283,24 +588,36 @@ fn triangle_area(p0: Point3, p1: Point3, p2: Point3) -> f64 {
     let vy = p2.y - p0.y;
     let vz = p2.z - p0.z;
 
-    let cx = uy * vz - uz * vy;
-    let cy = uz * vx - ux * vz;
-    let cz = ux * vy - uy * vx;
+    [
+        uy * vz - uz * vy,
+        uz * vx - ux * vz,
+        ux * vy - uy * vx,
+    ]
+}
 
-    0.5 * (cx * cx + cy * cy + cz * cz).sqrt()
+fn cross_norm(cross: [f64; 3]) -> f64 {
+    (cross[0] * cross[0] + cross[1] * cross[1] + cross[2] * cross[2]).sqrt()
 }
 
 fn success_result(mesh: OwnedMeshData) -> TruckMeshResult {
     let mut vertices = mesh.vertices;
+    let mut normals = mesh.normals;
     let mut indices = mesh.indices;
+    let mut submeshes = mesh.submeshes;
 
     let result = TruckMeshResult {
         vertices: vertices.as_mut_ptr(),
         vertex_count: vertices.len(),
         vertex_capacity: vertices.capacity(),
+        normals: normals.as_mut_ptr(),
+        normal_count: normals.len(),
+        normal_capacity: normals.capacity(),
         indices: indices.as_mut_ptr(),
         index_count: indices.len(),
         index_capacity: indices.capacity(),
+        submeshes: submeshes.as_mut_ptr(),
+        submesh_count: submeshes.len(),
+        submesh_capacity: submeshes.capacity(),
         volume: mesh.volume,
         surface_area: mesh.surface_area,
         bbox_min_x: mesh.bbox_min[0],
@@ -315,7 +632,9 @@ fn success_result(mesh: OwnedMeshData) -> TruckMeshResult {
     };
 
     mem::forget(vertices);
+    mem::forget(normals);
     mem::forget(indices);
+    mem::forget(submeshes);
     result
 }
 
@@ -328,9 +647,15 @@ fn make_error_result(code: TruckErrorCode, message: impl Into<String>) -> *mut T
         vertices: ptr::null_mut(),
         vertex_count: 0,
         vertex_capacity: 0,
+        normals: ptr::null_mut(),
+        normal_count: 0,
+        normal_capacity: 0,
         indices: ptr::null_mut(),
         index_count: 0,
         index_capacity: 0,
+        submeshes: ptr::null_mut(),
+        submesh_count: 0,
+        submesh_capacity: 0,
         volume: 0.0,
         surface_area: 0.0,
         bbox_min_x: 0.0,